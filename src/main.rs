@@ -1,3 +1,473 @@
+use std::env;
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Annualized failure rate assumed for each node when estimating durability.
+const DEFAULT_NODE_AFR: f64 = 0.02;
+
+/// Time to replace a failed node and rebuild its chunks.
+const DEFAULT_MTTR: Duration = Duration::from_secs(24 * 60 * 60);
+
+const HOURS_PER_YEAR: f64 = 365.25 * 24.0;
+
+/// Upper bound on parity chunks considered when recommending a layout.
+const MAX_RECOMMENDED_PARITY: usize = 32;
+
+/// Shortest MTTR accepted on the command line; well above `Duration`'s
+/// nanosecond resolution so the requested value is used as given.
+const MIN_MTTR: Duration = Duration::from_secs(1);
+
+/// Shape of an erasure-coded layout: `data_chunks + parity_chunks` chunks
+/// spread over `node_count` nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Config {
+    data_chunks: usize,
+    parity_chunks: usize,
+    node_count: usize,
+}
+
+/// Reasons a layout is rejected by [`Config::new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigError {
+    NoDataChunks,
+    TooManyChunks,
+    TooFewNodes { node_count: usize, min_nodes: usize },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NoDataChunks => write!(f, "data_chunks must be at least 1"),
+            ConfigError::TooManyChunks => write!(f, "data_chunks + parity_chunks overflows"),
+            ConfigError::TooFewNodes {
+                node_count,
+                min_nodes,
+            } => write!(
+                f,
+                "node_count {} is too small: at least {} nodes are needed",
+                node_count, min_nodes
+            ),
+        }
+    }
+}
+
+impl Config {
+    fn new(
+        data_chunks: usize,
+        parity_chunks: usize,
+        node_count: Option<usize>,
+    ) -> Result<Self, ConfigError> {
+        if data_chunks == 0 {
+            return Err(ConfigError::NoDataChunks);
+        }
+        let min_nodes = data_chunks
+            .checked_add(parity_chunks)
+            .ok_or(ConfigError::TooManyChunks)?;
+        let node_count = node_count.unwrap_or(min_nodes);
+        if node_count < min_nodes {
+            return Err(ConfigError::TooFewNodes {
+                node_count,
+                min_nodes,
+            });
+        }
+        Ok(Self {
+            data_chunks,
+            parity_chunks,
+            node_count,
+        })
+    }
+
+    fn total_chunks(&self) -> usize {
+        self.data_chunks + self.parity_chunks
+    }
+
+    /// Raw bytes stored per byte of user data.
+    fn overhead_ratio(&self) -> f64 {
+        self.total_chunks() as f64 / self.data_chunks as f64
+    }
+
+    /// Fraction of raw capacity holding user data.
+    fn storage_efficiency(&self) -> f64 {
+        self.data_chunks as f64 / self.total_chunks() as f64
+    }
+
+    /// Chunks that can be lost while the object stays readable.
+    fn max_failures(&self) -> usize {
+        self.parity_chunks
+    }
+
+    fn min_nodes(&self) -> usize {
+        self.total_chunks()
+    }
+
+    /// Chunks read to rebuild a single lost chunk.
+    fn repair_read_amplification(&self) -> usize {
+        self.data_chunks
+    }
+}
+
+/// Estimates durability as "nines" of annual object survival.
+///
+/// Uses the classic MTTDL approximation for a stripe of `k + m` chunks on
+/// distinct nodes: data is lost when `m + 1` nodes fail before the earlier
+/// failures are repaired. The MTTDL is computed in log space so wide stripes
+/// neither underflow nor overflow; the result is clamped at zero.
+///
+/// The approximation assumes repairs finish well before the next failure,
+/// i.e. `stripe * failure_rate * mttr < 1`. Outside that range it stops
+/// being meaningful (more parity would look less durable), so `None` is
+/// returned instead.
+fn durability_nines(config: &Config, node_afr: f64, mttr: Duration) -> Option<f64> {
+    let log_failure_rate = (node_afr / HOURS_PER_YEAR).log10();
+    let log_mttr_hours = (mttr.as_secs_f64() / 3600.0).log10();
+    let log_repair_rate = -log_mttr_hours;
+    let stripe = config.total_chunks();
+
+    if (stripe as f64).log10() + log_failure_rate + log_mttr_hours >= 0.0 {
+        return None;
+    }
+
+    let log_loss_rate: f64 = (0..=config.parity_chunks)
+        .map(|i| log_failure_rate + ((stripe - i) as f64).log10())
+        .sum();
+    let log_mttdl_hours = config.parity_chunks as f64 * log_repair_rate - log_loss_rate;
+
+    // Expected data-loss events per year; when it would underflow, the annual
+    // loss probability equals it and the nines follow directly from the logs.
+    let log_losses_per_year = HOURS_PER_YEAR.log10() - log_mttdl_hours;
+    let nines = if log_losses_per_year < -300.0 {
+        -log_losses_per_year
+    } else {
+        let annual_loss = -(-(10f64.powf(log_losses_per_year))).exp_m1();
+        -annual_loss.log10()
+    };
+
+    Some(if nines > 0.0 { nines } else { 0.0 })
+}
+
+/// Finds the smallest parity count that reaches `target_nines` of durability
+/// for `data_chunks` data chunks, using one node per chunk.
+fn recommend_config(
+    target_nines: f64,
+    node_afr: f64,
+    mttr: Duration,
+    data_chunks: usize,
+) -> Result<Config, String> {
+    for parity_chunks in 0..=MAX_RECOMMENDED_PARITY {
+        let config = Config::new(data_chunks, parity_chunks, None).map_err(|e| e.to_string())?;
+        match durability_nines(&config, node_afr, mttr) {
+            Some(nines) if nines >= target_nines => return Ok(config),
+            Some(_) => {}
+            // Wider stripes only move further out of the model's range.
+            None => {
+                return Err(format!(
+                "failures outpace repairs for a {}-chunk stripe; durability model does not apply",
+                config.total_chunks()
+            ))
+            }
+        }
+    }
+    Err(format!(
+        "{} nines is not reachable with up to {} parity chunks",
+        target_nines, MAX_RECOMMENDED_PARITY
+    ))
+}
+
+fn print_info(config: &Config, node_afr: f64, mttr: Duration) {
+    println!(
+        "Configuration: {} data + {} parity chunks on {} nodes",
+        config.data_chunks, config.parity_chunks, config.node_count
+    );
+    println!(
+        "  Storage overhead:          {:.2}x",
+        config.overhead_ratio()
+    );
+    println!(
+        "  Storage efficiency:        {:.1}%",
+        config.storage_efficiency() * 100.0
+    );
+    println!("  Max tolerable failures:    {}", config.max_failures());
+    println!("  Minimum nodes:             {}", config.min_nodes());
+    println!(
+        "  Repair read amplification: {}x",
+        config.repair_read_amplification()
+    );
+    let durability = match durability_nines(config, node_afr, mttr) {
+        Some(nines) => format!("{:.1} nines", nines),
+        None => "out of model range".to_string(),
+    };
+    println!(
+        "  Durability:                {} (node AFR {:.1}%, MTTR {})",
+        durability,
+        node_afr * 100.0,
+        format_mttr(mttr)
+    );
+}
+
+fn format_mttr(mttr: Duration) -> String {
+    let hours = mttr.as_secs_f64() / 3600.0;
+    if hours >= 0.1 {
+        format!("{:.1}h", hours)
+    } else {
+        format!("{:.0}s", mttr.as_secs_f64())
+    }
+}
+
+fn parse_info_args(args: &[String]) -> Result<Config, String> {
+    let mut data = None;
+    let mut parity = None;
+    let mut nodes = None;
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        let slot = match flag.as_str() {
+            "--data" => &mut data,
+            "--parity" => &mut parity,
+            "--nodes" => &mut nodes,
+            other => return Err(format!("unknown option '{}'", other)),
+        };
+        *slot = Some(parse_value(flag, take_value(flag, &mut iter)?)?);
+    }
+
+    let data = data.ok_or("--data is required")?;
+    let parity = parity.ok_or("--parity is required")?;
+    Config::new(data, parity, nodes).map_err(|e| match e {
+        ConfigError::NoDataChunks => "--data must be at least 1".to_string(),
+        ConfigError::TooManyChunks => {
+            format!("--data {} plus --parity {} is too large", data, parity)
+        }
+        ConfigError::TooFewNodes {
+            node_count,
+            min_nodes,
+        } => format!(
+            "--nodes {} is too small: {}+{} chunks need at least {} nodes",
+            node_count, data, parity, min_nodes
+        ),
+    })
+}
+
+struct RecommendArgs {
+    data_chunks: usize,
+    target_nines: f64,
+    node_afr: f64,
+    mttr: Duration,
+}
+
+fn parse_recommend_args(args: &[String]) -> Result<RecommendArgs, String> {
+    let mut data = None;
+    let mut nines = None;
+    let mut afr = DEFAULT_NODE_AFR;
+    let mut mttr_hours = DEFAULT_MTTR.as_secs_f64() / 3600.0;
+
+    let mut iter = args.iter();
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--data" => data = Some(parse_value(flag, take_value(flag, &mut iter)?)?),
+            "--nines" => nines = Some(parse_non_negative(flag, take_value(flag, &mut iter)?)?),
+            "--afr" => afr = parse_positive(flag, take_value(flag, &mut iter)?)?,
+            "--mttr-hours" => mttr_hours = parse_positive(flag, take_value(flag, &mut iter)?)?,
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+
+    let data_chunks = data.ok_or("--data is required")?;
+    // Validate the widest layout the search can reach, so it never fails midway.
+    Config::new(data_chunks, MAX_RECOMMENDED_PARITY, None).map_err(|e| match e {
+        ConfigError::NoDataChunks => "--data must be at least 1".to_string(),
+        ConfigError::TooManyChunks => format!("--data {} is too large", data_chunks),
+        other => other.to_string(),
+    })?;
+    let mttr = match Duration::try_from_secs_f64(mttr_hours * 3600.0) {
+        Ok(mttr) if mttr >= MIN_MTTR => mttr,
+        Ok(_) => return Err("--mttr-hours must be at least one second".to_string()),
+        Err(_) => return Err("--mttr-hours is out of range".to_string()),
+    };
+
+    Ok(RecommendArgs {
+        data_chunks,
+        target_nines: nines.ok_or("--nines is required")?,
+        node_afr: afr,
+        mttr,
+    })
+}
+
+fn take_value<'a>(
+    flag: &str,
+    iter: &mut impl Iterator<Item = &'a String>,
+) -> Result<&'a str, String> {
+    iter.next()
+        .map(String::as_str)
+        .ok_or_else(|| format!("{} requires a value", flag))
+}
+
+fn parse_value<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+fn parse_positive(flag: &str, value: &str) -> Result<f64, String> {
+    match parse_value::<f64>(flag, value)? {
+        number if number > 0.0 && number.is_finite() => Ok(number),
+        _ => Err(format!("{} must be a positive finite number", flag)),
+    }
+}
+
+fn parse_non_negative(flag: &str, value: &str) -> Result<f64, String> {
+    match parse_value::<f64>(flag, value)? {
+        number if number >= 0.0 && number.is_finite() => Ok(number),
+        _ => Err(format!("{} must be a non-negative finite number", flag)),
+    }
+}
+
+fn usage() -> &'static str {
+    "usage: erasure info --data N --parity M [--nodes K]\n       \
+     erasure recommend --data N --nines X [--afr RATE] [--mttr-hours H]"
+}
+
+fn exit_with_usage(error: &str) -> ! {
+    eprintln!("error: {}\n{}", error, usage());
+    process::exit(2);
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        None => exit_with_usage("missing command"),
+        Some("info") => match parse_info_args(&args[1..]) {
+            Ok(config) => print_info(&config, DEFAULT_NODE_AFR, DEFAULT_MTTR),
+            Err(e) => exit_with_usage(&e),
+        },
+        Some("recommend") => {
+            let request = parse_recommend_args(&args[1..]).unwrap_or_else(|e| exit_with_usage(&e));
+            match recommend_config(
+                request.target_nines,
+                request.node_afr,
+                request.mttr,
+                request.data_chunks,
+            ) {
+                Ok(config) => print_info(&config, request.node_afr, request.mttr),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        Some(other) => exit_with_usage(&format!("unknown command '{}'", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recommended_config_is_the_smallest_meeting_target() {
+        let target = 11.0;
+        let config = recommend_config(target, DEFAULT_NODE_AFR, DEFAULT_MTTR, 10).unwrap();
+
+        assert_eq!(config.data_chunks, 10);
+        assert_eq!(config.node_count, config.min_nodes());
+        assert!(durability_nines(&config, DEFAULT_NODE_AFR, DEFAULT_MTTR).unwrap() >= target);
+
+        let one_less = Config::new(10, config.parity_chunks - 1, None).unwrap();
+        assert!(durability_nines(&one_less, DEFAULT_NODE_AFR, DEFAULT_MTTR).unwrap() < target);
+    }
+
+    #[test]
+    fn config_rejects_chunk_count_overflow() {
+        assert_eq!(
+            Config::new(usize::MAX, 1, None),
+            Err(ConfigError::TooManyChunks)
+        );
+        assert_eq!(
+            Config::new(1, usize::MAX, Some(5)),
+            Err(ConfigError::TooManyChunks)
+        );
+    }
+
+    #[test]
+    fn durability_stays_finite_and_non_negative() {
+        let wide = Config::new(4, 200, None).unwrap();
+        let nines = durability_nines(&wide, DEFAULT_NODE_AFR, DEFAULT_MTTR).unwrap();
+        assert!(nines.is_finite() && nines > 100.0, "{}", nines);
+
+        let certain_loss = Config::new(1, 0, None).unwrap();
+        let nines = durability_nines(&certain_loss, 1000.0, Duration::from_secs(3600)).unwrap();
+        assert_eq!(nines.to_bits(), 0.0f64.to_bits());
+    }
+
+    #[test]
+    fn durability_is_none_when_failures_outpace_repairs() {
+        let huge = Config::new(10, 100_000_000, None).unwrap();
+        assert_eq!(
+            durability_nines(&huge, DEFAULT_NODE_AFR, DEFAULT_MTTR),
+            None
+        );
+    }
+
+    #[test]
+    fn durability_matches_direct_mttdl_formula() {
+        let config = Config::new(4, 2, None).unwrap();
+        let failure_rate = DEFAULT_NODE_AFR / HOURS_PER_YEAR;
+        let mttdl_hours = (1.0f64 / 24.0).powi(2) / (failure_rate.powi(3) * 6.0 * 5.0 * 4.0);
+        let expected = -(-(-HOURS_PER_YEAR / mttdl_hours).exp_m1()).log10();
+
+        let nines = durability_nines(&config, DEFAULT_NODE_AFR, DEFAULT_MTTR).unwrap();
+        assert!((nines - expected).abs() < 1e-9, "{} vs {}", nines, expected);
+    }
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn out_of_range_mttr_is_rejected() {
+        for mttr in ["1e20", "inf", "1e-30", "1e-12"] {
+            let result = parse_recommend_args(&args(&[
+                "--data",
+                "4",
+                "--nines",
+                "11",
+                "--mttr-hours",
+                mttr,
+            ]));
+            assert!(result.is_err(), "--mttr-hours {} was accepted", mttr);
+        }
+    }
+
+    #[test]
+    fn recommend_reports_unknown_option_before_missing_value() {
+        let error = parse_recommend_args(&args(&["--data", "4", "--bogus"]))
+            .err()
+            .unwrap();
+        assert_eq!(error, "unknown option '--bogus'");
+    }
+
+    #[test]
+    fn recommend_rejects_data_that_would_overflow() {
+        let error =
+            parse_recommend_args(&args(&["--data", "18446744073709551615", "--nines", "11"]))
+                .err()
+                .unwrap();
+        assert_eq!(error, "--data 18446744073709551615 is too large");
+    }
+
+    #[test]
+    fn mttr_is_printed_with_fixed_precision() {
+        assert_eq!(format_mttr(DEFAULT_MTTR), "24.0h");
+        assert_eq!(
+            format_mttr(Duration::from_secs_f64(0.3333 * 3600.0)),
+            "0.3h"
+        );
+        assert_eq!(format_mttr(Duration::from_secs(30)), "30s");
+    }
+
+    #[test]
+    fn unreachable_target_is_an_error() {
+        assert!(recommend_config(1000.0, DEFAULT_NODE_AFR, DEFAULT_MTTR, 4).is_err());
+    }
 }
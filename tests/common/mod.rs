@@ -0,0 +1,8 @@
+use std::process::{Command, Output};
+
+pub fn erasure(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_EraseCoding"))
+        .args(args)
+        .output()
+        .expect("failed to run binary")
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::erasure;
+
+#[test]
+fn info_prints_properties_for_known_config() {
+    let output = erasure(&["info", "--data", "4", "--parity", "2"]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("4 data + 2 parity chunks on 6 nodes"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Storage overhead:          1.50x"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Max tolerable failures:    2"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Minimum nodes:             6"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("Repair read amplification: 4x"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn info_rejects_too_few_nodes() {
+    let output = erasure(&["info", "--data", "4", "--parity", "2", "--nodes", "5"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("need at least 6 nodes"), "{}", stderr);
+}
+
+#[test]
+fn info_rejects_zero_data_chunks() {
+    let output = erasure(&["info", "--data", "0", "--parity", "2"]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("--data must be at least 1"), "{}", stderr);
+}
+
+#[test]
+fn info_rejects_chunk_count_overflow() {
+    for args in [
+        &["info", "--data", "18446744073709551615", "--parity", "1"][..],
+        &[
+            "info",
+            "--data",
+            "1",
+            "--parity",
+            "18446744073709551615",
+            "--nodes",
+            "5",
+        ][..],
+    ] {
+        let output = erasure(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("is too large"), "{:?}: {}", args, stderr);
+    }
+}
+
+#[test]
+fn info_reports_out_of_model_range() {
+    let output = erasure(&["info", "--data", "10", "--parity", "100000000"]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Durability:                out of model range"),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn missing_command_prints_usage() {
+    let output = erasure(&[]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("usage:"), "{}", stderr);
+}
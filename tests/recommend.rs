@@ -0,0 +1,50 @@
+mod common;
+
+use common::erasure;
+
+#[test]
+fn recommend_reports_durability_for_requested_afr() {
+    let output = erasure(&[
+        "recommend",
+        "--data",
+        "4",
+        "--nines",
+        "11",
+        "--afr",
+        "0.001",
+    ]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("node AFR 0.1%, MTTR 24.0h"), "{}", stdout);
+
+    let nines: f64 = stdout
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Durability:"))
+        .and_then(|rest| rest.split_whitespace().next())
+        .and_then(|value| value.parse().ok())
+        .expect("durability line");
+    assert!(nines >= 11.0, "{}", stdout);
+}
+
+#[test]
+fn recommend_rejects_bad_arguments_with_usage() {
+    for command in [
+        "recommend --data 4 --nines 11 --mttr-hours 1e20",
+        "recommend --data 4 --nines 11 --mttr-hours inf",
+        "recommend --data 4 --nines 11 --mttr-hours 1e-12",
+        "recommend --data 0 --nines 11",
+        "recommend --data 18446744073709551615 --nines 11",
+        "recommend --data 4 --nines 11 --bogus",
+        "recommend --data 4 --nines NaN",
+        "recommend --data 4 --nines inf",
+        "recommend --data 4 --nines -5",
+    ] {
+        let args: Vec<&str> = command.split_whitespace().collect();
+        let output = erasure(&args);
+        assert_eq!(output.status.code(), Some(2), "{}", command);
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        assert!(stderr.contains("usage:"), "{}: {}", command, stderr);
+    }
+}